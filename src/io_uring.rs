@@ -0,0 +1,144 @@
+//! Batched packet I/O over io_uring.
+//!
+//! The synchronous [`RawPacketStream`](crate::RawPacketStream) /
+//! [`DgramPacketStream`](crate::DgramPacketStream) path costs one `read`/`write`
+//! syscall per frame. [`PacketRing`] drives the same bound fd through io_uring
+//! instead: register a pool of buffers once, submit many
+//! `IORING_OP_READ_FIXED`/`IORING_OP_WRITE_FIXED` operations, harvest all their
+//! completions with a single `io_uring_enter`, and recycle the buffers by index
+//! rather than by pointer.
+
+use std::io::{Error, Result};
+use std::os::unix::io::{AsRawFd, RawFd};
+
+use io_uring::{opcode, types, IoUring};
+
+/// An io_uring engine bound to a packet socket fd, owning a fixed pool of
+/// registered buffers addressed by index.
+pub struct PacketRing {
+    ring: IoUring,
+    fd: RawFd,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl PacketRing {
+    /// Create an engine with `entries` submission slots and `buffers` receive
+    /// buffers of `buf_size` bytes each, registered with the kernel up front.
+    pub fn new(fd: RawFd, entries: u32, buffers: usize, buf_size: usize) -> Result<Self> {
+        let ring = IoUring::new(entries)?;
+        let mut pool: Vec<Vec<u8>> = (0..buffers).map(|_| vec![0u8; buf_size]).collect();
+        let iovecs: Vec<libc::iovec> = pool
+            .iter_mut()
+            .map(|b| libc::iovec {
+                iov_base: b.as_mut_ptr() as *mut libc::c_void,
+                iov_len: b.len(),
+            })
+            .collect();
+        // Safety: the buffers outlive the ring — both are owned by `self` and
+        // unregistered/dropped together.
+        unsafe {
+            ring.submitter().register_buffers(&iovecs)?;
+        }
+        Ok(PacketRing {
+            ring,
+            fd,
+            buffers: pool,
+        })
+    }
+
+    /// Borrow a pooled buffer by index, e.g. to read a completed receive.
+    pub fn buffer(&self, index: usize) -> &[u8] {
+        &self.buffers[index]
+    }
+
+    /// Fill a pooled buffer by index before submitting a send.
+    pub fn buffer_mut(&mut self, index: usize) -> &mut [u8] {
+        &mut self.buffers[index]
+    }
+
+    /// Queue a receive into the registered buffer `index`. The buffer index is
+    /// echoed back as the completion's `user_data`.
+    pub fn submit_recv(&mut self, index: usize) -> Result<()> {
+        let buf = &mut self.buffers[index];
+        let op = opcode::ReadFixed::new(
+            types::Fd(self.fd),
+            buf.as_mut_ptr(),
+            buf.len() as u32,
+            index as u16,
+        )
+        .build()
+        .user_data(index as u64);
+        // Safety: the buffer referenced by `op` is registered and stays alive
+        // for as long as the ring.
+        unsafe { self.push(op) }
+    }
+
+    /// Queue a send of `len` bytes from the registered buffer `index`.
+    pub fn submit_send(&mut self, index: usize, len: usize) -> Result<()> {
+        let buf = &self.buffers[index];
+        let op = opcode::WriteFixed::new(
+            types::Fd(self.fd),
+            buf.as_ptr(),
+            len as u32,
+            index as u16,
+        )
+        .build()
+        .user_data(index as u64);
+        // Safety: see `submit_recv`.
+        unsafe { self.push(op) }
+    }
+
+    unsafe fn push(&mut self, entry: io_uring::squeue::Entry) -> Result<()> {
+        loop {
+            // Drop the `SubmissionQueue` borrow before deciding whether to
+            // submit, so the full-queue path can re-borrow `self.ring`.
+            let full = self.ring.submission().push(&entry).is_err();
+            if !full {
+                return Ok(());
+            }
+            // Submission queue full: flush it to the kernel and retry.
+            self.ring.submit()?;
+        }
+    }
+
+    /// Submit every queued operation with a single `io_uring_enter`, waiting
+    /// for at least `want` completions.
+    pub fn submit_and_wait(&mut self, want: usize) -> Result<usize> {
+        self.ring.submit_and_wait(want)
+    }
+
+    /// Drain the completion queue, yielding `(buffer_index, Result<bytes>)`
+    /// for each finished operation so buffers can be recycled by index.
+    pub fn completions(&mut self) -> Completions<'_> {
+        Completions {
+            cq: self.ring.completion(),
+        }
+    }
+}
+
+/// Iterator over harvested io_uring completions.
+pub struct Completions<'a> {
+    cq: io_uring::cqueue::CompletionQueue<'a>,
+}
+
+impl<'a> Iterator for Completions<'a> {
+    type Item = (usize, Result<usize>);
+
+    fn next(&mut self) -> Option<(usize, Result<usize>)> {
+        let cqe = self.cq.next()?;
+        let index = cqe.user_data() as usize;
+        let res = cqe.result();
+        let out = if res < 0 {
+            Err(Error::from_raw_os_error(-res))
+        } else {
+            Ok(res as usize)
+        };
+        Some((index, out))
+    }
+}
+
+impl AsRawFd for PacketRing {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
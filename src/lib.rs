@@ -0,0 +1,17 @@
+mod sync;
+
+pub use sync::*;
+
+/// Optional io_uring engine for batched packet send/receive.
+///
+/// Gated behind the `io-uring` feature so platforms without io_uring still
+/// build against the synchronous API.
+#[cfg(feature = "io-uring")]
+pub mod io_uring;
+
+/// mio event-source integration for readiness-based async runtimes.
+///
+/// Gated behind the `mio` feature; adds `event::Source` impls for the packet
+/// streams so they can be driven by a reactor.
+#[cfg(feature = "mio")]
+mod mio;
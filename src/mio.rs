@@ -0,0 +1,58 @@
+//! mio event-source integration.
+//!
+//! Implements mio's [`event::Source`](mio::event::Source) for the packet
+//! streams so they can be registered with a reactor and polled for
+//! `READABLE`/`WRITABLE` readiness alongside other sources, letting the
+//! existing non-blocking mode participate in a tokio/async-std event loop
+//! without hand-rolled `AsRawFd` + `AsyncFd` glue.
+//!
+//! Put the socket in non-blocking mode with
+//! [`set_non_blocking`](crate::RawPacketStream::set_non_blocking) before
+//! registering it.
+
+use std::io::Result;
+use std::os::unix::io::AsRawFd;
+
+use mio::event::Source;
+use mio::unix::SourceFd;
+use mio::{Interest, Registry, Token};
+
+use crate::{DgramPacketStream, RawPacketStream};
+
+impl Source for RawPacketStream {
+    fn register(&mut self, registry: &Registry, token: Token, interests: Interest) -> Result<()> {
+        SourceFd(&self.as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> Result<()> {
+        SourceFd(&self.as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> Result<()> {
+        SourceFd(&self.as_raw_fd()).deregister(registry)
+    }
+}
+
+impl Source for DgramPacketStream {
+    fn register(&mut self, registry: &Registry, token: Token, interests: Interest) -> Result<()> {
+        SourceFd(&self.as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> Result<()> {
+        SourceFd(&self.as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> Result<()> {
+        SourceFd(&self.as_raw_fd()).deregister(registry)
+    }
+}
@@ -3,17 +3,26 @@
 
 use std::convert::TryInto;
 use std::io::{Error, ErrorKind, Read, Result, Write};
-use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, RawFd};
+use std::sync::atomic::{fence, Ordering};
+use std::os::unix::io::{
+    AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd,
+};
 
 use libc::{sockaddr_ll, sockaddr_storage, socket, packet_mreq, setsockopt};
 use libc::{
     AF_PACKET, ETH_P_ALL, MSG_DONTWAIT, PACKET_ADD_MEMBERSHIP, PACKET_DROP_MEMBERSHIP,
     PACKET_MR_PROMISC, SOCK_DGRAM, SOCK_RAW, SOL_PACKET, SOL_SOCKET, SO_ATTACH_FILTER,
 };
+use libc::{
+    tpacket2_hdr, tpacket3_hdr, tpacket_block_desc, tpacket_req, tpacket_req3, PACKET_RX_RING,
+    PACKET_TX_RING, PACKET_VERSION, POLLIN, TP_STATUS_AVAILABLE, TP_STATUS_COPY,
+    TP_STATUS_KERNEL, TP_STATUS_LOSING, TP_STATUS_SEND_REQUEST, TP_STATUS_USER,
+    TP_STATUS_WRONG_FORMAT,
+};
 
 /// Packet sockets are used to receive or send raw packets at OSI 2 level.
-#[derive(Debug, Clone)]
-pub struct RawPacketStream(RawFd);
+#[derive(Debug)]
+pub struct RawPacketStream(OwnedFd);
 
 pub type Filter = (u16, u8, u8, u32);
 pub type FilterProgram = Vec<Filter>;
@@ -52,7 +61,17 @@ impl RawPacketStream {
         if fd == -1 {
             return Err(Error::last_os_error());
         }
-        Ok(RawPacketStream(fd as RawFd))
+        // Safety: `fd` is a fresh, exclusively owned descriptor.
+        Ok(RawPacketStream(unsafe { OwnedFd::from_raw_fd(fd as RawFd) }))
+    }
+
+    /// Duplicate the underlying descriptor with `F_DUPFD_CLOEXEC`, returning an
+    /// independently owned stream.
+    ///
+    /// This replaces a derived `Clone`: cloning a bare descriptor and dropping
+    /// both copies would close the same fd twice.
+    pub fn try_clone(&self) -> Result<Self> {
+        Ok(RawPacketStream(self.0.try_clone()?))
     }
 
     /// Bind socket to an interface (by name).
@@ -76,7 +95,7 @@ impl RawPacketStream {
             (*sll).sll_ifindex = ifindex;
 
             let sa = (&ss as *const libc::sockaddr_storage) as *const libc::sockaddr;
-            let res = libc::bind(self.0, sa, std::mem::size_of::<sockaddr_ll>() as u32);
+            let res = libc::bind(self.0.as_raw_fd(), sa, std::mem::size_of::<sockaddr_ll>() as u32);
             if res == -1 {
                 return Err(Error::last_os_error());
             }
@@ -105,7 +124,7 @@ impl RawPacketStream {
             mreq.mr_ifindex = idx;
             mreq.mr_type = PACKET_MR_PROMISC as u16;
 
-            let res = setsockopt(self.0, SOL_PACKET, packet_membership, (&mreq as *const packet_mreq) as *const libc::c_void, std::mem::size_of::<packet_mreq>() as u32);
+            let res = setsockopt(self.0.as_raw_fd(), SOL_PACKET, packet_membership, (&mreq as *const packet_mreq) as *const libc::c_void, std::mem::size_of::<packet_mreq>() as u32);
             if res == -1 {
                 return Err(Error::last_os_error());
             }
@@ -126,7 +145,7 @@ impl RawPacketStream {
         };
 
         unsafe {
-            let res = setsockopt(self.0, SOL_SOCKET, SO_ATTACH_FILTER, &program as *const _ as *const libc::c_void, std::mem::size_of::<sock_fprog>() as u32);
+            let res = setsockopt(self.0.as_raw_fd(), SOL_SOCKET, SO_ATTACH_FILTER, &program as *const _ as *const libc::c_void, std::mem::size_of::<sock_fprog>() as u32);
             if res == -1 {
                 return Err(Error::last_os_error());
             }
@@ -142,7 +161,7 @@ impl RawPacketStream {
     pub(crate) fn drain_internal(&self) {
         let mut buf = [0u8; 1];
         loop {
-            let rv = unsafe { libc::recv(self.0, buf.as_mut_ptr() as *mut libc::c_void, buf.len(), MSG_DONTWAIT) };
+            let rv = unsafe { libc::recv(self.0.as_raw_fd(), buf.as_mut_ptr() as *mut libc::c_void, buf.len(), MSG_DONTWAIT) };
             if rv == -1 { break; }
         }
     }
@@ -150,9 +169,9 @@ impl RawPacketStream {
     // Put the file descriptor in non-blocking mode.
     pub fn set_non_blocking(&mut self) -> Result<()> {
         unsafe {
-            let mut res = libc::fcntl(self.0, libc::F_GETFL);
+            let mut res = libc::fcntl(self.0.as_raw_fd(), libc::F_GETFL);
             if res != -1 {
-                res = libc::fcntl(self.0, libc::F_SETFL, res | libc::O_NONBLOCK);
+                res = libc::fcntl(self.0.as_raw_fd(), libc::F_SETFL, res | libc::O_NONBLOCK);
             }
             if res == -1 {
                 return Err(Error::last_os_error());
@@ -160,6 +179,25 @@ impl RawPacketStream {
         }
         Ok(())
     }
+
+    /// Switch this (bound) socket into zero-copy `TPACKET_V3` capture mode,
+    /// consuming the stream and returning a [`RingReader`] over an mmap'd
+    /// `PACKET_RX_RING`.
+    ///
+    /// The kernel fills fixed-size blocks and flips their status to
+    /// `TP_STATUS_USER` when they are ready; the returned reader hands each
+    /// block back with `TP_STATUS_KERNEL` once the consumer is done with it.
+    pub fn into_ring_reader(self, config: RingConfig) -> Result<RingReader> {
+        RingReader::new(self, config)
+    }
+
+    /// Switch this socket into zero-copy `PACKET_TX_RING` transmit mode,
+    /// consuming the stream and returning a [`TxRing`] over an mmap'd ring.
+    ///
+    /// See [`TxRing`] for the fill/flush protocol.
+    pub fn into_tx_ring(self, config: RingConfig) -> Result<TxRing> {
+        TxRing::new(self.0.as_raw_fd(), TxOwner::Raw(self), config)
+    }
 }
 
 fn index_by_name(name: &str) -> Result<i32> {
@@ -176,8 +214,8 @@ fn index_by_name(name: &str) -> Result<i32> {
     Ok(idx as i32)
 }
 
-fn read_fd(fd: RawFd, buf: &mut [u8]) -> Result<usize> {
-    let rv = unsafe { libc::read(fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len()) };
+fn read_fd(fd: BorrowedFd<'_>, buf: &mut [u8]) -> Result<usize> {
+    let rv = unsafe { libc::read(fd.as_raw_fd(), buf.as_mut_ptr() as *mut libc::c_void, buf.len()) };
     if rv < 0 {
         return Err(Error::last_os_error());
     }
@@ -187,18 +225,18 @@ fn read_fd(fd: RawFd, buf: &mut [u8]) -> Result<usize> {
 
 impl Read for RawPacketStream {
     fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
-        read_fd(self.0, buf)
+        read_fd(self.0.as_fd(), buf)
     }
 }
 
-impl<'a> Read for &'a RawPacketStream {
+impl Read for &RawPacketStream {
     fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
-        read_fd(self.0, buf)
+        read_fd(self.0.as_fd(), buf)
     }
 }
 
-fn write_fd(fd: RawFd, buf: &[u8]) -> Result<usize> {
-    let rv = unsafe { libc::write(fd, buf.as_ptr() as *const libc::c_void, buf.len()) };
+fn write_fd(fd: BorrowedFd<'_>, buf: &[u8]) -> Result<usize> {
+    let rv = unsafe { libc::write(fd.as_raw_fd(), buf.as_ptr() as *const libc::c_void, buf.len()) };
     if rv < 0 {
         return Err(Error::last_os_error());
     }
@@ -208,7 +246,7 @@ fn write_fd(fd: RawFd, buf: &[u8]) -> Result<usize> {
 
 impl Write for RawPacketStream {
     fn write(&mut self, buf: &[u8]) -> Result<usize> {
-        write_fd(self.0, buf)
+        write_fd(self.0.as_fd(), buf)
     }
 
     fn flush(&mut self) -> Result<()> {
@@ -216,9 +254,9 @@ impl Write for RawPacketStream {
     }
 }
 
-impl<'a> Write for &'a RawPacketStream {
+impl Write for &RawPacketStream {
     fn write(&mut self, buf: &[u8]) -> Result<usize> {
-        write_fd(self.0, buf)
+        write_fd(self.0.as_fd(), buf)
     }
 
     fn flush(&mut self) -> Result<()> {
@@ -228,36 +266,499 @@ impl<'a> Write for &'a RawPacketStream {
 
 impl IntoRawFd for RawPacketStream {
     fn into_raw_fd(self) -> RawFd {
-        self.0
+        self.0.into_raw_fd()
     }
 }
 
 impl AsRawFd for RawPacketStream {
     fn as_raw_fd(&self) -> RawFd {
-        self.0
+        self.0.as_raw_fd()
+    }
+}
+
+impl AsFd for RawPacketStream {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_fd()
     }
 }
 
 impl FromRawFd for RawPacketStream {
     unsafe fn from_raw_fd(fd: RawFd) -> RawPacketStream {
+        RawPacketStream(OwnedFd::from_raw_fd(fd))
+    }
+}
+
+impl From<OwnedFd> for RawPacketStream {
+    fn from(fd: OwnedFd) -> RawPacketStream {
         RawPacketStream(fd)
     }
 }
 
-impl Drop for RawPacketStream {
+impl From<RawPacketStream> for OwnedFd {
+    fn from(stream: RawPacketStream) -> OwnedFd {
+        stream.0
+    }
+}
+
+/// Geometry of a `PACKET_RX_RING`.
+///
+/// `block_size` must be a multiple of the page size and of `frame_size`, and
+/// the total mapping is `block_size * block_nr` bytes. `retire_blk_tov` is the
+/// block retirement timeout in milliseconds after which the kernel hands a
+/// partially filled block back to userspace.
+#[derive(Debug, Clone, Copy)]
+pub struct RingConfig {
+    pub block_size: u32,
+    pub block_nr: u32,
+    pub frame_size: u32,
+    pub frame_nr: u32,
+    pub retire_blk_tov: u32,
+}
+
+impl Default for RingConfig {
+    fn default() -> Self {
+        // 64 blocks of 256 KiB, frames of 2 KiB; a 16 MiB ring that comfortably
+        // absorbs line-rate bursts.
+        RingConfig {
+            block_size: 1 << 18,
+            block_nr: 64,
+            frame_size: 1 << 11,
+            frame_nr: (1 << 18) / (1 << 11) * 64,
+            retire_blk_tov: 60,
+        }
+    }
+}
+
+/// A zero-copy receive ring backed by `TPACKET_V3`.
+///
+/// The socket fd is owned for the lifetime of the reader so the mmap stays
+/// valid; dropping the reader unmaps the ring and closes the socket.
+#[derive(Debug)]
+pub struct RingReader {
+    inner: RawPacketStream,
+    map: *mut libc::c_void,
+    map_len: usize,
+    block_size: usize,
+    block_nr: usize,
+    next_block: usize,
+}
+
+impl RingReader {
+    fn new(inner: RawPacketStream, config: RingConfig) -> Result<Self> {
+        let fd = inner.0.as_raw_fd();
+        let version = libc::tpacket_versions::TPACKET_V3 as libc::c_int;
+        unsafe {
+            let res = setsockopt(
+                fd,
+                SOL_PACKET,
+                PACKET_VERSION,
+                &version as *const _ as *const libc::c_void,
+                std::mem::size_of::<libc::c_int>() as u32,
+            );
+            if res == -1 {
+                return Err(Error::last_os_error());
+            }
+
+            let mut req: tpacket_req3 = std::mem::zeroed();
+            req.tp_block_size = config.block_size;
+            req.tp_block_nr = config.block_nr;
+            req.tp_frame_size = config.frame_size;
+            req.tp_frame_nr = config.frame_nr;
+            req.tp_retire_blk_tov = config.retire_blk_tov;
+            let res = setsockopt(
+                fd,
+                SOL_PACKET,
+                PACKET_RX_RING,
+                &req as *const _ as *const libc::c_void,
+                std::mem::size_of::<tpacket_req3>() as u32,
+            );
+            if res == -1 {
+                return Err(Error::last_os_error());
+            }
+
+            let map_len = config.block_size as usize * config.block_nr as usize;
+            let map = libc::mmap(
+                std::ptr::null_mut(),
+                map_len,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED,
+                fd,
+                0,
+            );
+            if map == libc::MAP_FAILED {
+                return Err(Error::last_os_error());
+            }
+
+            Ok(RingReader {
+                inner,
+                map,
+                map_len,
+                block_size: config.block_size as usize,
+                block_nr: config.block_nr as usize,
+                next_block: 0,
+            })
+        }
+    }
+
+    fn block_desc(&self, idx: usize) -> *mut tpacket_block_desc {
+        unsafe { self.map.add(idx * self.block_size) as *mut tpacket_block_desc }
+    }
+
+    /// Wait for the next ready block and borrow it.
+    ///
+    /// Blocks in `poll(POLLIN)` until the kernel flips the next block to
+    /// `TP_STATUS_USER`. The returned [`Block`] yields the frames it contains
+    /// as borrowed slices; handing it back to the kernel happens when the
+    /// `Block` is dropped, so no yielded slice may outlive it.
+    pub fn block(&mut self) -> Result<Block<'_>> {
+        let idx = self.next_block;
+        let desc = self.block_desc(idx);
+        loop {
+            let status = unsafe { (*desc).hdr.bh1.block_status };
+            if status & TP_STATUS_USER != 0 {
+                break;
+            }
+            let mut pfd = libc::pollfd {
+                fd: self.inner.0.as_raw_fd(),
+                events: POLLIN,
+                revents: 0,
+            };
+            let rv = unsafe { libc::poll(&mut pfd, 1, -1) };
+            if rv == -1 {
+                let err = Error::last_os_error();
+                if err.kind() == ErrorKind::Interrupted {
+                    continue;
+                }
+                return Err(err);
+            }
+        }
+
+        // Acquire the block contents the kernel published under `TP_STATUS_USER`:
+        // pairs with the kernel's store barrier so the frame data and descriptor
+        // fields read below are not stale on weakly-ordered architectures.
+        fence(Ordering::Acquire);
+
+        self.next_block = (idx + 1) % self.block_nr;
+        let bh1 = unsafe { &(*desc).hdr.bh1 };
+        Ok(Block {
+            desc,
+            base: desc as *const u8,
+            num_pkts: bh1.num_pkts,
+            offset_first: bh1.offset_to_first_pkt,
+            lost: bh1.block_status & TP_STATUS_LOSING != 0,
+            _reader: std::marker::PhantomData,
+        })
+    }
+}
+
+impl Drop for RingReader {
     fn drop(&mut self) {
         unsafe {
-            libc::close(self.0);
+            libc::munmap(self.map, self.map_len);
         }
     }
 }
 
-#[derive(Debug, Clone)]
+/// A single ready block borrowed from a [`RingReader`].
+///
+/// Handed back to the kernel (`TP_STATUS_KERNEL`) on drop.
+#[derive(Debug)]
+pub struct Block<'a> {
+    desc: *mut tpacket_block_desc,
+    base: *const u8,
+    num_pkts: u32,
+    offset_first: u32,
+    lost: bool,
+    _reader: std::marker::PhantomData<&'a mut RingReader>,
+}
+
+impl<'a> Block<'a> {
+    /// Whether the kernel signalled `TP_STATUS_LOSING` for this block, i.e.
+    /// packets were dropped because the ring could not keep up.
+    pub fn packets_lost(&self) -> bool {
+        self.lost
+    }
+
+    /// Iterate the frames in this block, yielding a borrowed [`Frame`] each.
+    pub fn frames(&self) -> Frames<'_> {
+        Frames {
+            base: self.base,
+            offset: self.offset_first,
+            remaining: self.num_pkts,
+            _block: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a> Drop for Block<'a> {
+    fn drop(&mut self) {
+        // Publish any reads/writes of the block before the kernel may reuse it,
+        // then return ownership of the block.
+        fence(Ordering::Release);
+        unsafe {
+            (*self.desc).hdr.bh1.block_status = TP_STATUS_KERNEL;
+        }
+    }
+}
+
+/// A captured frame borrowed from a [`Block`].
+#[derive(Debug)]
+pub struct Frame<'a> {
+    /// The frame payload, starting at the MAC header.
+    pub data: &'a [u8],
+    /// Capture timestamp, seconds.
+    pub sec: u32,
+    /// Capture timestamp, nanoseconds.
+    pub nsec: u32,
+    /// The kernel had to copy this (over-sized) frame into the ring; `data`
+    /// holds only the first `tp_snaplen` bytes.
+    pub copied: bool,
+}
+
+/// Iterator over the frames of a [`Block`], walking the `tp_next_offset`
+/// linked list.
+#[derive(Debug)]
+pub struct Frames<'a> {
+    base: *const u8,
+    offset: u32,
+    remaining: u32,
+    _block: std::marker::PhantomData<&'a Block<'a>>,
+}
+
+impl<'a> Iterator for Frames<'a> {
+    type Item = Frame<'a>;
+
+    fn next(&mut self) -> Option<Frame<'a>> {
+        if self.remaining == 0 || self.offset == 0 {
+            return None;
+        }
+        unsafe {
+            let hdr = self.base.add(self.offset as usize) as *const tpacket3_hdr;
+            let data = std::slice::from_raw_parts(
+                self.base
+                    .add(self.offset as usize + (*hdr).tp_mac as usize),
+                (*hdr).tp_snaplen as usize,
+            );
+            let frame = Frame {
+                data,
+                sec: (*hdr).tp_sec,
+                nsec: (*hdr).tp_nsec,
+                copied: (*hdr).tp_status & TP_STATUS_COPY != 0,
+            };
+            let next = (*hdr).tp_next_offset;
+            self.remaining -= 1;
+            if next == 0 {
+                self.offset = 0;
+            } else {
+                self.offset += next;
+            }
+            Some(frame)
+        }
+    }
+}
+
+// Offset of the payload within a TX frame slot: the `tpacket2_hdr` rounded up
+// to the ring alignment (`TPACKET_ALIGNMENT` == 16).
+const fn tx_frame_hdrlen() -> usize {
+    let raw = std::mem::size_of::<tpacket2_hdr>();
+    (raw + 15) & !15
+}
+
+// Keeps the socket that backs a TX ring alive (and closed on drop) without
+// leaking which concrete stream type created the ring. The payload is never
+// read back out — it is held purely as an ownership guard.
+#[derive(Debug)]
+#[allow(dead_code)]
+enum TxOwner {
+    Raw(RawPacketStream),
+    Dgram(DgramPacketStream),
+}
+
+/// A zero-copy transmit ring backed by `PACKET_TX_RING`.
+///
+/// Bulk senders fill frame slots in place instead of paying a `write()` /
+/// `sendto()` per frame: [`reserve`](TxRing::reserve) hands out the payload
+/// region of the next writable slot, [`commit`](TxFrame::commit) marks it
+/// `TP_STATUS_SEND_REQUEST`, and a single [`flush`](TxRing::flush) kicks the
+/// kernel to transmit every queued slot at once.
+///
+/// The ring is set up as `TPACKET_V2` with a `tpacket_req`: the V3 block
+/// retirement machinery ([`RingConfig::retire_blk_tov`]) is RX-only and is
+/// rejected by the kernel on a TX ring, so only the `block_*`/`frame_*`
+/// geometry of [`RingConfig`] applies here.
+#[derive(Debug)]
+pub struct TxRing {
+    _owner: TxOwner,
+    fd: RawFd,
+    map: *mut libc::c_void,
+    map_len: usize,
+    frame_size: usize,
+    frame_nr: usize,
+    next_frame: usize,
+}
+
+impl TxRing {
+    fn new(fd: RawFd, owner: TxOwner, config: RingConfig) -> Result<Self> {
+        let version = libc::tpacket_versions::TPACKET_V2 as libc::c_int;
+        unsafe {
+            let res = setsockopt(
+                fd,
+                SOL_PACKET,
+                PACKET_VERSION,
+                &version as *const _ as *const libc::c_void,
+                std::mem::size_of::<libc::c_int>() as u32,
+            );
+            if res == -1 {
+                return Err(Error::last_os_error());
+            }
+
+            // A V2 TX ring takes a plain `tpacket_req`; there is no retirement
+            // timeout to set (and a non-zero one is rejected).
+            let mut req: tpacket_req = std::mem::zeroed();
+            req.tp_block_size = config.block_size;
+            req.tp_block_nr = config.block_nr;
+            req.tp_frame_size = config.frame_size;
+            req.tp_frame_nr = config.frame_nr;
+            let res = setsockopt(
+                fd,
+                SOL_PACKET,
+                PACKET_TX_RING,
+                &req as *const _ as *const libc::c_void,
+                std::mem::size_of::<tpacket_req>() as u32,
+            );
+            if res == -1 {
+                return Err(Error::last_os_error());
+            }
+
+            let map_len = config.block_size as usize * config.block_nr as usize;
+            let map = libc::mmap(
+                std::ptr::null_mut(),
+                map_len,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED,
+                fd,
+                0,
+            );
+            if map == libc::MAP_FAILED {
+                return Err(Error::last_os_error());
+            }
+
+            Ok(TxRing {
+                _owner: owner,
+                fd,
+                map,
+                map_len,
+                frame_size: config.frame_size as usize,
+                frame_nr: config.frame_nr as usize,
+                next_frame: 0,
+            })
+        }
+    }
+
+    fn frame_hdr(&self, idx: usize) -> *mut tpacket2_hdr {
+        unsafe { self.map.add(idx * self.frame_size) as *mut tpacket2_hdr }
+    }
+
+    fn count_status(&self, status: u32) -> usize {
+        (0..self.frame_nr)
+            .filter(|&idx| unsafe { (*self.frame_hdr(idx)).tp_status } == status)
+            .count()
+    }
+
+    /// Reserve the next writable frame slot.
+    ///
+    /// A slot is writable when it is `TP_STATUS_AVAILABLE` or when a previous
+    /// send left it `TP_STATUS_WRONG_FORMAT` — the latter is surfaced so the
+    /// caller can rewrite and recommit it (see [`TxFrame::wrong_format`]).
+    /// Returns [`ErrorKind::WouldBlock`] when the next slot is still owned by
+    /// the kernel (ring full), matching the non-blocking syscall path.
+    pub fn reserve(&mut self) -> Result<TxFrame<'_>> {
+        let idx = self.next_frame;
+        let hdr = self.frame_hdr(idx);
+        let status = unsafe { (*hdr).tp_status };
+        if status != TP_STATUS_AVAILABLE && status != TP_STATUS_WRONG_FORMAT {
+            return Err(ErrorKind::WouldBlock.into());
+        }
+        self.next_frame = (idx + 1) % self.frame_nr;
+        let data = unsafe {
+            std::slice::from_raw_parts_mut(
+                (hdr as *mut u8).add(tx_frame_hdrlen()),
+                self.frame_size - tx_frame_hdrlen(),
+            )
+        };
+        Ok(TxFrame { hdr, data })
+    }
+
+    /// Kick the kernel to transmit every committed (`TP_STATUS_SEND_REQUEST`)
+    /// slot with a single `send()`, returning how many frames the kernel
+    /// actually accepted.
+    ///
+    /// The count is reconciled from the ring itself: committed slots before
+    /// the kick, minus those still pending afterwards, minus any newly
+    /// rejected with `TP_STATUS_WRONG_FORMAT` (inspect them via a fresh
+    /// [`reserve`](TxRing::reserve)). The kick is always sent with
+    /// `MSG_DONTWAIT`, so `flush` does not block whatever the socket's mode; a
+    /// full send queue surfaces as [`ErrorKind::WouldBlock`].
+    pub fn flush(&mut self) -> Result<usize> {
+        let queued = self.count_status(TP_STATUS_SEND_REQUEST);
+        let wrong_before = self.count_status(TP_STATUS_WRONG_FORMAT);
+        let rv = unsafe { libc::send(self.fd, std::ptr::null(), 0, MSG_DONTWAIT) };
+        if rv == -1 {
+            return Err(Error::last_os_error());
+        }
+        let still_pending = self.count_status(TP_STATUS_SEND_REQUEST);
+        let wrong_after = self.count_status(TP_STATUS_WRONG_FORMAT);
+        // Only the kernel changes slot states between the two counts; compute
+        // in signed space and clamp to the number that was actually queued so
+        // the result stays within 0..=queued.
+        let newly_wrong = (wrong_after as i64 - wrong_before as i64).max(0);
+        let accepted = queued as i64 - still_pending as i64 - newly_wrong;
+        Ok(accepted.clamp(0, queued as i64) as usize)
+    }
+}
+
+impl Drop for TxRing {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.map, self.map_len);
+        }
+    }
+}
+
+/// A reserved transmit frame. Write the payload into [`data`](TxFrame::data),
+/// then [`commit`](TxFrame::commit) to queue it.
+#[derive(Debug)]
+pub struct TxFrame<'a> {
+    hdr: *mut tpacket2_hdr,
+    /// The writable payload region of the slot, starting at the MAC header.
+    pub data: &'a mut [u8],
+}
+
+impl<'a> TxFrame<'a> {
+    /// Record the payload length and flip the slot to `TP_STATUS_SEND_REQUEST`
+    /// so the next [`flush`](TxRing::flush) transmits it.
+    pub fn commit(self, len: usize) {
+        unsafe {
+            (*self.hdr).tp_len = len as u32;
+            (*self.hdr).tp_snaplen = len as u32;
+            (*self.hdr).tp_status = TP_STATUS_SEND_REQUEST;
+        }
+    }
+
+    /// Whether the kernel rejected this slot on the previous send with
+    /// `TP_STATUS_WRONG_FORMAT` (malformed frame), leaving it for the caller
+    /// to rewrite.
+    pub fn wrong_format(&self) -> bool {
+        unsafe { (*self.hdr).tp_status & TP_STATUS_WRONG_FORMAT != 0 }
+    }
+}
+
+#[derive(Debug)]
 pub struct DgramPacketStream {
     ifindex: i32,
     dest: [u8; 8],
     protocol_nbo: u16,
-    fd: RawFd,
+    fd: OwnedFd,
 }
 
 impl DgramPacketStream {
@@ -272,7 +773,19 @@ impl DgramPacketStream {
             ifindex,
             dest,
             protocol_nbo: protocol.to_be(),
-            fd: fd as RawFd,
+            // Safety: `fd` is a fresh, exclusively owned descriptor.
+            fd: unsafe { OwnedFd::from_raw_fd(fd as RawFd) },
+        })
+    }
+
+    /// Duplicate the underlying descriptor with `F_DUPFD_CLOEXEC`, returning an
+    /// independently owned stream pointing at the same socket.
+    pub fn try_clone(&self) -> Result<Self> {
+        Ok(DgramPacketStream {
+            ifindex: self.ifindex,
+            dest: self.dest,
+            protocol_nbo: self.protocol_nbo,
+            fd: self.fd.try_clone()?,
         })
     }
 
@@ -287,9 +800,9 @@ impl DgramPacketStream {
 
     pub fn set_non_blocking(&mut self) -> Result<()> {
         unsafe {
-            let mut res = libc::fcntl(self.fd, libc::F_GETFL);
+            let mut res = libc::fcntl(self.fd.as_raw_fd(), libc::F_GETFL);
             if res != -1 {
-                res = libc::fcntl(self.fd, libc::F_SETFL, res | libc::O_NONBLOCK);
+                res = libc::fcntl(self.fd.as_raw_fd(), libc::F_SETFL, res | libc::O_NONBLOCK);
             }
             if res == -1 {
                 return Err(Error::last_os_error());
@@ -297,9 +810,17 @@ impl DgramPacketStream {
         }
         Ok(())
     }
+
+    /// Switch this socket into zero-copy `PACKET_TX_RING` transmit mode,
+    /// consuming the stream and returning a [`TxRing`]. The frame payload must
+    /// include the link-layer header; the `dest`/`ifindex` set on the stream
+    /// are not consulted once the ring is in use.
+    pub fn into_tx_ring(self, config: RingConfig) -> Result<TxRing> {
+        TxRing::new(self.fd.as_raw_fd(), TxOwner::Dgram(self), config)
+    }
 }
 
-fn send_to(fd: RawFd, ifindex: i32, dest: [u8; 8], protocol_nbo: u16, buf: &[u8]) -> Result<usize> {
+fn send_to(fd: BorrowedFd<'_>, ifindex: i32, dest: [u8; 8], protocol_nbo: u16, buf: &[u8]) -> Result<usize> {
     let res;
     unsafe {
         let mut ss: sockaddr_storage = std::mem::zeroed();
@@ -311,7 +832,7 @@ fn send_to(fd: RawFd, ifindex: i32, dest: [u8; 8], protocol_nbo: u16, buf: &[u8]
 
         let sa = (&ss as *const libc::sockaddr_storage) as *const libc::sockaddr;
         res = libc::sendto(
-            fd,
+            fd.as_raw_fd(),
             buf.as_ptr() as *const libc::c_void,
             buf.len(),
             0,
@@ -327,7 +848,7 @@ fn send_to(fd: RawFd, ifindex: i32, dest: [u8; 8], protocol_nbo: u16, buf: &[u8]
 
 impl Write for DgramPacketStream {
     fn write(&mut self, buf: &[u8]) -> Result<usize> {
-        send_to(self.fd, self.ifindex, self.dest, self.protocol_nbo, buf)
+        send_to(self.fd.as_fd(), self.ifindex, self.dest, self.protocol_nbo, buf)
     }
 
     fn flush(&mut self) -> Result<()> {
@@ -335,9 +856,9 @@ impl Write for DgramPacketStream {
     }
 }
 
-impl<'a> Write for &'a DgramPacketStream {
+impl Write for &DgramPacketStream {
     fn write(&mut self, buf: &[u8]) -> Result<usize> {
-        send_to(self.fd, self.ifindex, self.dest, self.protocol_nbo, buf)
+        send_to(self.fd.as_fd(), self.ifindex, self.dest, self.protocol_nbo, buf)
     }
 
     fn flush(&mut self) -> Result<()> {
@@ -347,6 +868,104 @@ impl<'a> Write for &'a DgramPacketStream {
 
 impl AsRawFd for DgramPacketStream {
     fn as_raw_fd(&self) -> RawFd {
-        self.fd
+        self.fd.as_raw_fd()
+    }
+}
+
+impl AsFd for DgramPacketStream {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl From<DgramPacketStream> for OwnedFd {
+    fn from(stream: DgramPacketStream) -> OwnedFd {
+        stream.fd
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::marker::PhantomData;
+
+    #[test]
+    fn ring_config_default_geometry() {
+        let cfg = RingConfig::default();
+        // block_size must be an integral number of frames, and frame_nr must
+        // cover every frame across every block.
+        assert_eq!(cfg.block_size % cfg.frame_size, 0);
+        assert_eq!(
+            cfg.frame_nr,
+            (cfg.block_size / cfg.frame_size) * cfg.block_nr
+        );
+        // The mapping spans exactly block_size * block_nr bytes.
+        assert_eq!(
+            cfg.block_size as usize * cfg.block_nr as usize,
+            16 * 1024 * 1024
+        );
+    }
+
+    // Write a minimal `tpacket3_hdr` into `buf` at `off`, linking to the next
+    // frame `next` bytes further on (0 to terminate the list).
+    fn put_frame(buf: &mut [u8], off: usize, next: u32, mac: u16, snaplen: u32, sec: u32) {
+        unsafe {
+            let hdr = buf.as_mut_ptr().add(off) as *mut tpacket3_hdr;
+            (*hdr).tp_next_offset = next;
+            (*hdr).tp_mac = mac;
+            (*hdr).tp_snaplen = snaplen;
+            (*hdr).tp_sec = sec;
+            (*hdr).tp_nsec = 0;
+            (*hdr).tp_status = 0;
+        }
+    }
+
+    #[test]
+    fn frames_walk_and_terminate() {
+        let mac = tx_frame_hdrlen() as u16; // any header-sized payload offset
+        let stride = 512usize;
+        // The first frame starts at a non-zero offset (offset 0 is the
+        // end-of-list sentinel), matching offset_to_first_pkt in a real block.
+        let first = stride;
+        let mut buf = vec![0u8; stride * 3];
+        put_frame(&mut buf, first, stride as u32, mac, 10, 1);
+        put_frame(&mut buf, first + stride, 0, mac, 20, 3);
+
+        let frames = Frames {
+            base: buf.as_ptr(),
+            offset: first as u32,
+            remaining: 2,
+            _block: PhantomData,
+        };
+        let collected: Vec<_> = frames.map(|f| (f.data.len(), f.sec)).collect();
+        assert_eq!(collected, vec![(10, 1), (20, 3)]);
+    }
+
+    #[test]
+    fn frames_stop_at_remaining_count() {
+        let mac = tx_frame_hdrlen() as u16;
+        let stride = 512usize;
+        let first = stride;
+        let mut buf = vec![0u8; stride * 3];
+        // Both frames link onwards, but remaining caps the walk at one.
+        put_frame(&mut buf, first, stride as u32, mac, 10, 1);
+        put_frame(&mut buf, first + stride, stride as u32, mac, 20, 3);
+
+        let frames = Frames {
+            base: buf.as_ptr(),
+            offset: first as u32,
+            remaining: 1,
+            _block: PhantomData,
+        };
+        assert_eq!(frames.count(), 1);
+    }
+
+    #[test]
+    fn tx_frame_hdrlen_is_aligned() {
+        let hdrlen = tx_frame_hdrlen();
+        // Rounded up to TPACKET_ALIGNMENT (16), still covering the header.
+        assert_eq!(hdrlen % 16, 0);
+        assert!(hdrlen >= std::mem::size_of::<tpacket2_hdr>());
+        assert!(hdrlen < std::mem::size_of::<tpacket2_hdr>() + 16);
     }
 }